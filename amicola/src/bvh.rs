@@ -0,0 +1,162 @@
+//! AABB bounding-volume hierarchy over a collection of curves.
+
+use crate::monotonics::*;
+
+/// Primitives stop splitting once a node holds this many or fewer.
+const LEAF_SIZE: usize = 4;
+
+/// A binary tree of `Bounds` over borrowed [`Curve`]s.
+pub struct Bvh<'a> {
+    root: Node<'a>,
+}
+
+enum Node<'a> {
+    Leaf { bounds: Bounds, items: Vec<&'a dyn Curve> },
+    Branch { bounds: Bounds, left: Box<Node<'a>>, right: Box<Node<'a>> },
+}
+
+impl<'a> Bvh<'a> {
+    /// Builds the hierarchy top-down: each node splits its primitives along
+    /// the axis of greatest centroid spread using a median partition (an
+    /// unstable nth-element, avoiding a full sort), recursing until a leaf
+    /// holds `LEAF_SIZE` or fewer.
+    pub fn new(curves: impl IntoIterator<Item = &'a dyn Curve>) -> Self {
+        Bvh { root: build(curves.into_iter().collect()) }
+    }
+
+    /// Primitives whose `Bounds.bottom..=top` contains `y`.
+    pub fn query_y(&self, y: f32) -> impl Iterator<Item = &'a dyn Curve> {
+        let mut out = Vec::new();
+        self.root.query_y(y, &mut out);
+        out.into_iter()
+    }
+
+    /// Primitives whose bounds contain `p`.
+    pub fn query_point(&self, p: V2) -> impl Iterator<Item = &'a dyn Curve> {
+        self.query_bounds(&Bounds { left: p.x, right: p.x, bottom: p.y, top: p.y })
+    }
+
+    /// Primitives whose bounds overlap `region`.
+    pub fn query_bounds(&self, region: &Bounds) -> impl Iterator<Item = &'a dyn Curve> {
+        let mut out = Vec::new();
+        self.root.query_bounds(region, &mut out);
+        out.into_iter()
+    }
+}
+
+impl<'a> Node<'a> {
+    fn bounds(&self) -> &Bounds {
+        match self {
+            Node::Leaf { bounds, .. } => bounds,
+            Node::Branch { bounds, .. } => bounds,
+        }
+    }
+
+    fn query_y(&self, y: f32, out: &mut Vec<&'a dyn Curve>) {
+        if !(self.bounds().bottom <= y && y <= self.bounds().top) {
+            return;
+        }
+
+        match self {
+            Node::Leaf { items, .. } => {
+                out.extend(items.iter().copied().filter(|c| c.bounds().bottom <= y && y <= c.bounds().top))
+            }
+            Node::Branch { left, right, .. } => {
+                left.query_y(y, out);
+                right.query_y(y, out);
+            }
+        }
+    }
+
+    fn query_bounds(&self, region: &Bounds, out: &mut Vec<&'a dyn Curve>) {
+        if !overlaps(self.bounds(), region) {
+            return;
+        }
+
+        match self {
+            Node::Leaf { items, .. } => out.extend(items.iter().copied().filter(|c| overlaps(c.bounds(), region))),
+            Node::Branch { left, right, .. } => {
+                left.query_bounds(region, out);
+                right.query_bounds(region, out);
+            }
+        }
+    }
+}
+
+fn build<'a>(mut items: Vec<&'a dyn Curve>) -> Node<'a> {
+    let bounds = union_bounds(items.iter().map(|c| c.bounds()));
+    if items.len() <= LEAF_SIZE {
+        return Node::Leaf { bounds, items };
+    }
+
+    let centroids: Vec<V2> = items.iter().map(|c| centroid(*c)).collect();
+    let (min_x, max_x, min_y, max_y) = centroids.iter().fold(
+        (f32::INFINITY, f32::NEG_INFINITY, f32::INFINITY, f32::NEG_INFINITY),
+        |(min_x, max_x, min_y, max_y), c| (min_x.min(c.x), max_x.max(c.x), min_y.min(c.y), max_y.max(c.y)),
+    );
+
+    let split_on_x = (max_x - min_x) >= (max_y - min_y);
+    let mid = items.len() / 2;
+    if split_on_x {
+        items.select_nth_unstable_by(mid, |a, b| centroid(*a).x.partial_cmp(&centroid(*b).x).unwrap());
+    } else {
+        items.select_nth_unstable_by(mid, |a, b| centroid(*a).y.partial_cmp(&centroid(*b).y).unwrap());
+    }
+
+    let right_items = items.split_off(mid);
+    Node::Branch { bounds, left: Box::new(build(items)), right: Box::new(build(right_items)) }
+}
+
+fn centroid(curve: &dyn Curve) -> V2 {
+    let bounds = curve.bounds();
+    V2::new((bounds.left + bounds.right) / 2.0, (bounds.bottom + bounds.top) / 2.0)
+}
+
+fn overlaps(a: &Bounds, b: &Bounds) -> bool {
+    a.left <= b.right && b.left <= a.right && a.bottom <= b.top && b.bottom <= a.top
+}
+
+fn union_bounds<'a>(mut bounds: impl Iterator<Item = &'a Bounds>) -> Bounds {
+    let first = match bounds.next() {
+        Some(b) => *b,
+        None => return Bounds { left: 0.0, right: 0.0, bottom: 0.0, top: 0.0 },
+    };
+
+    bounds.fold(first, |acc, b| Bounds {
+        left: acc.left.min(b.left),
+        right: acc.right.max(b.right),
+        bottom: acc.bottom.min(b.bottom),
+        top: acc.top.max(b.top),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn query_y_only_returns_overlapping_rows() {
+        let segments = vec![
+            LineSegment::new(V2::new(0.0, 0.0), V2::new(1.0, 1.0)),
+            LineSegment::new(V2::new(5.0, 5.0), V2::new(6.0, 6.0)),
+            LineSegment::new(V2::new(10.0, 10.0), V2::new(11.0, 11.0)),
+        ];
+        let curves: Vec<&dyn Curve> = segments.iter().map(|s| s as &dyn Curve).collect();
+        let bvh = Bvh::new(curves);
+
+        assert_eq!(bvh.query_y(0.5).count(), 1);
+        assert_eq!(bvh.query_y(5.5).count(), 1);
+        assert_eq!(bvh.query_y(20.0).count(), 0);
+    }
+
+    #[test]
+    fn query_point_matches_containing_bounds() {
+        let segments =
+            vec![LineSegment::new(V2::new(0.0, 0.0), V2::new(2.0, 2.0)), LineSegment::new(V2::new(4.0, 4.0), V2::new(6.0, 6.0))];
+        let curves: Vec<&dyn Curve> = segments.iter().map(|s| s as &dyn Curve).collect();
+        let bvh = Bvh::new(curves);
+
+        assert_eq!(bvh.query_point(V2::new(1.0, 1.0)).count(), 1);
+        assert_eq!(bvh.query_point(V2::new(100.0, 100.0)).count(), 0);
+    }
+}
@@ -0,0 +1,160 @@
+//! Marching-squares iso-contours.
+
+use crate::monotonics::*;
+
+/// Which side of a grid cell an edge crossing was found on.
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Edge {
+    Bottom,
+    Right,
+    Top,
+    Left,
+}
+
+/// Samples `field` on a `resolution`-by-`resolution` grid over `bounds` and
+/// returns the segments where the field crosses `threshold`.
+pub fn contour(field: &impl Fn(V2) -> f32, bounds: &Bounds, resolution: usize, threshold: f32) -> Vec<LineSegment> {
+    let grid = sample_grid(field, bounds, resolution);
+    extract(&grid, bounds, resolution, threshold)
+}
+
+/// Extracts one contour per threshold in `thresholds`, sampling the field
+/// only once, producing nested contour bands.
+pub fn contour_bands(
+    field: &impl Fn(V2) -> f32,
+    bounds: &Bounds,
+    resolution: usize,
+    thresholds: &[f32],
+) -> Vec<Vec<LineSegment>> {
+    let grid = sample_grid(field, bounds, resolution);
+    thresholds.iter().map(|&threshold| extract(&grid, bounds, resolution, threshold)).collect()
+}
+
+/// Corner values at every grid point, `(resolution + 1)` per axis, row-major
+/// from the bottom-left.
+fn sample_grid(field: &impl Fn(V2) -> f32, bounds: &Bounds, resolution: usize) -> Vec<f32> {
+    let dx = (bounds.right - bounds.left) / resolution as f32;
+    let dy = (bounds.top - bounds.bottom) / resolution as f32;
+    let mut grid = Vec::with_capacity((resolution + 1) * (resolution + 1));
+    for j in 0..=resolution {
+        for i in 0..=resolution {
+            let p = V2::new(bounds.left + i as f32 * dx, bounds.bottom + j as f32 * dy);
+            grid.push(field(p));
+        }
+    }
+    grid
+}
+
+fn extract(grid: &[f32], bounds: &Bounds, resolution: usize, threshold: f32) -> Vec<LineSegment> {
+    let dx = (bounds.right - bounds.left) / resolution as f32;
+    let dy = (bounds.top - bounds.bottom) / resolution as f32;
+    let stride = resolution + 1;
+    let value_at = |i: usize, j: usize| grid[j * stride + i];
+
+    let mut segments = Vec::new();
+    for j in 0..resolution {
+        for i in 0..resolution {
+            let x0 = bounds.left + i as f32 * dx;
+            let y0 = bounds.bottom + j as f32 * dy;
+            let x1 = x0 + dx;
+            let y1 = y0 + dy;
+
+            let v_bl = value_at(i, j);
+            let v_br = value_at(i + 1, j);
+            let v_tr = value_at(i + 1, j + 1);
+            let v_tl = value_at(i, j + 1);
+
+            let case = (v_bl >= threshold) as u8
+                | ((v_br >= threshold) as u8) << 1
+                | ((v_tr >= threshold) as u8) << 2
+                | ((v_tl >= threshold) as u8) << 3;
+
+            let corner = |edge: Edge| -> V2 {
+                match edge {
+                    Edge::Bottom => lerp_edge(V2::new(x0, y0), v_bl, V2::new(x1, y0), v_br, threshold),
+                    Edge::Right => lerp_edge(V2::new(x1, y0), v_br, V2::new(x1, y1), v_tr, threshold),
+                    Edge::Top => lerp_edge(V2::new(x1, y1), v_tr, V2::new(x0, y1), v_tl, threshold),
+                    Edge::Left => lerp_edge(V2::new(x0, y1), v_tl, V2::new(x0, y0), v_bl, threshold),
+                }
+            };
+
+            let center = (v_bl + v_br + v_tr + v_tl) / 4.0;
+            for (a, b) in cell_edges(case, center, threshold) {
+                segments.push(LineSegment::new(corner(a), corner(b)));
+            }
+        }
+    }
+    segments
+}
+
+/// `edge_t = (threshold - v0) / (v1 - v0)`, applied along the segment `p0..p1`.
+fn lerp_edge(p0: V2, v0: f32, p1: V2, v1: f32, threshold: f32) -> V2 {
+    let t = (threshold - v0) / (v1 - v0);
+    p0 + (p1 - p0) * t
+}
+
+/// Standard marching-squares case table mapping a 4-bit corner case
+/// (bit 0 = bottom-left .. bit 3 = top-left, set when the corner is at or
+/// above `threshold`) to the edge pairs the contour crosses. Cases 5 and 10
+/// are the ambiguous saddles, resolved by sampling the cell center.
+fn cell_edges(case: u8, center: f32, threshold: f32) -> Vec<(Edge, Edge)> {
+    use Edge::*;
+    match case {
+        0 | 15 => vec![],
+        1 | 14 => vec![(Left, Bottom)],
+        2 | 13 => vec![(Bottom, Right)],
+        3 | 12 => vec![(Left, Right)],
+        4 | 11 => vec![(Right, Top)],
+        6 | 9 => vec![(Bottom, Top)],
+        7 | 8 => vec![(Left, Top)],
+        5 => {
+            if center >= threshold {
+                vec![(Left, Top), (Bottom, Right)]
+            } else {
+                vec![(Left, Bottom), (Right, Top)]
+            }
+        }
+        10 => {
+            if center >= threshold {
+                vec![(Left, Bottom), (Right, Top)]
+            } else {
+                vec![(Left, Top), (Bottom, Right)]
+            }
+        }
+        _ => unreachable!("case is a 4-bit index, 0..=15"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn bounds() -> Bounds { Bounds { left: -1.0, right: 1.0, bottom: -1.0, top: 1.0 } }
+
+    #[test]
+    fn circle_field_produces_a_closed_ring() {
+        let field = |p: V2| 1.0 - (p.x * p.x + p.y * p.y).sqrt();
+        let segments = contour(&field, &bounds(), 20, 0.0);
+        assert!(!segments.is_empty());
+    }
+
+    #[test]
+    fn threshold_outside_field_range_has_no_crossings() {
+        let field = |_p: V2| 0.0;
+        let segments = contour(&field, &bounds(), 10, 5.0);
+        assert!(segments.is_empty());
+    }
+
+    #[test]
+    fn bands_sample_the_field_once_per_call() {
+        let calls = std::cell::Cell::new(0usize);
+        let field = |p: V2| {
+            calls.set(calls.get() + 1);
+            p.x
+        };
+        let bands = contour_bands(&field, &bounds(), 10, &[-0.5, 0.0, 0.5]);
+        assert_eq!(calls.get(), 11 * 11, "one sample per grid corner, shared by every threshold");
+        assert_eq!(bands.len(), 3);
+        assert!(bands.iter().all(|b| !b.is_empty()));
+    }
+}
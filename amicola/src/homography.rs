@@ -0,0 +1,126 @@
+//! Projective (homography) transforms.
+
+use crate::monotonics::*;
+
+/// A 3x3 projective transform, row-major, with the bottom-right entry fixed
+/// at `1` as is conventional for a homography solved from point
+/// correspondences.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Homography {
+    matrix: [[f32; 3]; 3],
+}
+
+impl Homography {
+    /// Solves the four-point correspondence problem: finds the homography
+    /// mapping each `from[i]` to `to[i]`.
+    ///
+    /// Sets up the 8x8 linear system `(x, y, 1) -> (wx', wy', w)` implied by
+    /// the four correspondences and solves for the eight free coefficients
+    /// `a..h` via Gaussian elimination with partial pivoting. Returns `None`
+    /// if the correspondence is degenerate (e.g. three collinear points).
+    pub fn from_points(from: [V2; 4], to: [V2; 4]) -> Option<Self> {
+        let mut a = [[0.0f64; 8]; 8];
+        let mut b = [0.0f64; 8];
+
+        for i in 0..4 {
+            let (x, y) = (from[i].x as f64, from[i].y as f64);
+            let (u, v) = (to[i].x as f64, to[i].y as f64);
+
+            a[2 * i] = [x, y, 1.0, 0.0, 0.0, 0.0, -x * u, -y * u];
+            b[2 * i] = u;
+
+            a[2 * i + 1] = [0.0, 0.0, 0.0, x, y, 1.0, -x * v, -y * v];
+            b[2 * i + 1] = v;
+        }
+
+        let coeffs = solve(a, b)?;
+        Some(Homography {
+            matrix: [
+                [coeffs[0] as f32, coeffs[1] as f32, coeffs[2] as f32],
+                [coeffs[3] as f32, coeffs[4] as f32, coeffs[5] as f32],
+                [coeffs[6] as f32, coeffs[7] as f32, 1.0],
+            ],
+        })
+    }
+
+    /// Applies the transform to `p`, dividing through by the homogeneous `w`.
+    pub fn transform(&self, p: V2) -> V2 {
+        let [row_x, row_y, row_w] = self.matrix;
+        let w = row_w[0] * p.x + row_w[1] * p.y + row_w[2];
+        V2::new((row_x[0] * p.x + row_x[1] * p.y + row_x[2]) / w, (row_y[0] * p.x + row_y[1] * p.y + row_y[2]) / w)
+    }
+
+    /// Transforms both bookends of `segment` and rebuilds it.
+    pub fn transform_segment(&self, segment: &LineSegment) -> LineSegment {
+        let (start, end) = segment.bookends();
+        LineSegment::new(self.transform(start), self.transform(end))
+    }
+}
+
+/// Warps a whole collection of segments, such as an already-tessellated
+/// `Render`'s geometry, by `h`. A whole-`Render` warp pass is just this
+/// applied to every primitive's flattened edges.
+pub fn warp_segments(h: &Homography, segments: &[LineSegment]) -> Vec<LineSegment> {
+    segments.iter().map(|s| h.transform_segment(s)).collect()
+}
+
+/// Gaussian elimination with partial pivoting for an 8x8 system; returns
+/// `None` if the matrix is singular.
+fn solve(mut a: [[f64; 8]; 8], mut b: [f64; 8]) -> Option<[f64; 8]> {
+    for col in 0..8 {
+        let pivot = (col..8).max_by(|&r1, &r2| a[r1][col].abs().partial_cmp(&a[r2][col].abs()).unwrap())?;
+        if a[pivot][col].abs() < 1e-9 {
+            return None;
+        }
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+
+        for row in (col + 1)..8 {
+            let factor = a[row][col] / a[col][col];
+            for k in col..8 {
+                a[row][k] -= factor * a[col][k];
+            }
+            b[row] -= factor * b[col];
+        }
+    }
+
+    let mut x = [0.0; 8];
+    for row in (0..8).rev() {
+        let sum: f64 = ((row + 1)..8).map(|k| a[row][k] * x[k]).sum();
+        x[row] = (b[row] - sum) / a[row][row];
+    }
+    Some(x)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn identity_points_round_trip() {
+        let square = [V2::new(0.0, 0.0), V2::new(1.0, 0.0), V2::new(1.0, 1.0), V2::new(0.0, 1.0)];
+        let h = Homography::from_points(square, square).expect("non-degenerate correspondence");
+        for p in square {
+            let transformed = h.transform(p);
+            assert!((transformed.x - p.x).abs() < 1e-4);
+            assert!((transformed.y - p.y).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn keystone_warp_moves_the_top_edge() {
+        let from = [V2::new(0.0, 0.0), V2::new(1.0, 0.0), V2::new(1.0, 1.0), V2::new(0.0, 1.0)];
+        let to = [V2::new(0.0, 0.0), V2::new(1.0, 0.0), V2::new(0.8, 1.0), V2::new(0.2, 1.0)];
+        let h = Homography::from_points(from, to).expect("non-degenerate correspondence");
+        let warped = h.transform(V2::new(0.0, 1.0));
+        assert!((warped.x - 0.2).abs() < 1e-4);
+        assert!((warped.y - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn degenerate_points_return_none() {
+        let collinear = [V2::new(0.0, 0.0), V2::new(1.0, 0.0), V2::new(2.0, 0.0), V2::new(3.0, 0.0)];
+        let to = [V2::new(0.0, 0.0), V2::new(1.0, 1.0), V2::new(2.0, 2.0), V2::new(3.0, 3.0)];
+        assert_eq!(Homography::from_points(collinear, to), None);
+    }
+}
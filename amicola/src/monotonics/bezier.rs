@@ -0,0 +1,238 @@
+//! Bezier curves.
+
+use super::*;
+
+/// Maximum distance, in normalized space, a control point may stray from the
+/// chord before a cubic or quadratic segment is subdivided further.
+const FLATNESS_TOLERANCE: f32 = 0.005;
+
+/// Maximum recursion depth for de Casteljau subdivision, guarding against
+/// runaway recursion on degenerate control points that never flatten.
+const MAX_SUBDIVISION_DEPTH: u32 = 16;
+
+/// A quadratic Bezier curve, flattened to a chain of [`RasterableLineSegment`]s
+/// at construction so it can be sampled like any other [`Curve`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuadraticBezier {
+    p0: V2,
+    p1: V2,
+    p2: V2,
+    bounds: Bounds,
+    chain: Chain,
+}
+
+impl QuadraticBezier {
+    pub fn new(p0: V2, p1: V2, p2: V2) -> Self {
+        let mut points = vec![p0];
+        flatten_quadratic(p0, p1, p2, FLATNESS_TOLERANCE, 0, &mut points);
+
+        QuadraticBezier { p0, p1, p2, bounds: hull_bounds(&[p0, p1, p2]), chain: Chain::new(points) }
+    }
+}
+
+impl Curve for QuadraticBezier {
+    fn sample_y(&self, y: f32) -> Option<Intersection> { self.chain.sample_y(y) }
+
+    fn sample_x(&self, x: f32) -> Option<Intersection> { self.chain.sample_x(x) }
+
+    fn sample_t(&self, t: f32) -> Option<V2> { self.chain.sample_t(t) }
+
+    fn bounds(&self) -> &Bounds { &self.bounds }
+
+    fn bookends(&self) -> (V2, V2) { (self.p0, self.p2) }
+}
+
+/// A cubic Bezier curve, flattened to a chain of [`RasterableLineSegment`]s
+/// at construction so it can be sampled like any other [`Curve`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CubicBezier {
+    p0: V2,
+    p1: V2,
+    p2: V2,
+    p3: V2,
+    bounds: Bounds,
+    chain: Chain,
+}
+
+impl CubicBezier {
+    pub fn new(p0: V2, p1: V2, p2: V2, p3: V2) -> Self {
+        let mut points = vec![p0];
+        flatten_cubic(p0, p1, p2, p3, FLATNESS_TOLERANCE, 0, &mut points);
+
+        CubicBezier { p0, p1, p2, p3, bounds: hull_bounds(&[p0, p1, p2, p3]), chain: Chain::new(points) }
+    }
+}
+
+impl Curve for CubicBezier {
+    fn sample_y(&self, y: f32) -> Option<Intersection> { self.chain.sample_y(y) }
+
+    fn sample_x(&self, x: f32) -> Option<Intersection> { self.chain.sample_x(x) }
+
+    fn sample_t(&self, t: f32) -> Option<V2> { self.chain.sample_t(t) }
+
+    fn bounds(&self) -> &Bounds { &self.bounds }
+
+    fn bookends(&self) -> (V2, V2) { (self.p0, self.p3) }
+}
+
+/// The flattened polyline backing both bezier kinds, cached so `sample_t` can
+/// map `t` across the whole curve by arc length without re-flattening.
+#[derive(Debug, Clone, PartialEq)]
+struct Chain {
+    segments: Vec<RasterableLineSegment>,
+    cumulative_length: Vec<f32>,
+    length: f32,
+}
+
+impl Chain {
+    fn new(points: Vec<V2>) -> Self {
+        let mut segments = Vec::new();
+        let mut cumulative_length = Vec::new();
+        let mut length = 0.0;
+        for pair in points.windows(2) {
+            if let Some(segment) = RasterableLineSegment::new(pair[0], pair[1]) {
+                length += (pair[1] - pair[0]).norm();
+                cumulative_length.push(length);
+                segments.push(segment);
+            }
+        }
+
+        Chain { segments, cumulative_length, length }
+    }
+
+    fn sample_y(&self, y: f32) -> Option<Intersection> {
+        self.segments.iter().find_map(|segment| segment.sample_y(y))
+    }
+
+    fn sample_x(&self, x: f32) -> Option<Intersection> {
+        self.segments.iter().find_map(|segment| segment.sample_x(x))
+    }
+
+    fn sample_t(&self, t: f32) -> Option<V2> {
+        if t < 0.0 || t > 1.0 || self.segments.is_empty() {
+            return None;
+        }
+
+        let target = t * self.length;
+        let mut start_of_segment = 0.0;
+        for (segment, &end_of_segment) in self.segments.iter().zip(self.cumulative_length.iter()) {
+            if target <= end_of_segment {
+                let (a, b) = segment.bookends();
+                let segment_length = end_of_segment - start_of_segment;
+                let local_t = if segment_length > 0.0 {
+                    (target - start_of_segment) / segment_length
+                } else {
+                    0.0
+                };
+                return Some(a + (b - a) * local_t);
+            }
+            start_of_segment = end_of_segment;
+        }
+
+        self.segments.last().map(|segment| segment.bookends().1)
+    }
+}
+
+fn flatten_quadratic(p0: V2, p1: V2, p2: V2, tolerance: f32, depth: u32, out: &mut Vec<V2>) {
+    if depth >= MAX_SUBDIVISION_DEPTH || point_line_distance(p1, p0, p2) <= tolerance {
+        out.push(p2);
+        return;
+    }
+
+    let p01 = lerp(p0, p1, 0.5);
+    let p12 = lerp(p1, p2, 0.5);
+    let p012 = lerp(p01, p12, 0.5);
+    flatten_quadratic(p0, p01, p012, tolerance, depth + 1, out);
+    flatten_quadratic(p012, p12, p2, tolerance, depth + 1, out);
+}
+
+fn flatten_cubic(p0: V2, p1: V2, p2: V2, p3: V2, tolerance: f32, depth: u32, out: &mut Vec<V2>) {
+    let flat = point_line_distance(p1, p0, p3) <= tolerance && point_line_distance(p2, p0, p3) <= tolerance;
+    if depth >= MAX_SUBDIVISION_DEPTH || flat {
+        out.push(p3);
+        return;
+    }
+
+    let p01 = lerp(p0, p1, 0.5);
+    let p12 = lerp(p1, p2, 0.5);
+    let p23 = lerp(p2, p3, 0.5);
+    let p012 = lerp(p01, p12, 0.5);
+    let p123 = lerp(p12, p23, 0.5);
+    let p0123 = lerp(p012, p123, 0.5);
+    flatten_cubic(p0, p01, p012, p0123, tolerance, depth + 1, out);
+    flatten_cubic(p0123, p123, p23, p3, tolerance, depth + 1, out);
+}
+
+fn lerp(a: V2, b: V2, t: f32) -> V2 { a + (b - a) * t }
+
+/// Perpendicular distance from `p` to the line through `a` and `b`.
+fn point_line_distance(p: V2, a: V2, b: V2) -> f32 {
+    let baseline = b - a;
+    let baseline_length = baseline.norm();
+    if baseline_length == 0.0 {
+        return (p - a).norm();
+    }
+
+    let pa = p - a;
+    ((pa.x * baseline.y - pa.y * baseline.x) / baseline_length).abs()
+}
+
+fn hull_bounds(points: &[V2]) -> Bounds {
+    let mut left = points[0].x;
+    let mut right = points[0].x;
+    let mut bottom = points[0].y;
+    let mut top = points[0].y;
+    for p in &points[1..] {
+        left = left.min(p.x);
+        right = right.max(p.x);
+        bottom = bottom.min(p.y);
+        top = top.max(p.y);
+    }
+
+    Bounds { left, right, top, bottom }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn quadratic_bookends_are_endpoints() {
+        let curve = QuadraticBezier::new(V2::new(0.0, 0.0), V2::new(1.0, 2.0), V2::new(2.0, 0.0));
+        assert_eq!(curve.bookends(), (V2::new(0.0, 0.0), V2::new(2.0, 0.0)));
+    }
+
+    #[test]
+    fn cubic_bookends_are_endpoints() {
+        let curve = CubicBezier::new(
+            V2::new(0.0, 0.0),
+            V2::new(1.0, 2.0),
+            V2::new(2.0, 2.0),
+            V2::new(3.0, 0.0),
+        );
+        assert_eq!(curve.bookends(), (V2::new(0.0, 0.0), V2::new(3.0, 0.0)));
+    }
+
+    #[test]
+    fn cubic_sample_t_endpoints() {
+        let curve = CubicBezier::new(
+            V2::new(0.0, 0.0),
+            V2::new(1.0, 2.0),
+            V2::new(2.0, 2.0),
+            V2::new(3.0, 0.0),
+        );
+        assert_eq!(curve.sample_t(0.0), Some(V2::new(0.0, 0.0)));
+        assert_eq!(curve.sample_t(1.0), Some(V2::new(3.0, 0.0)));
+        assert_eq!(curve.sample_t(1.5), None);
+    }
+
+    #[test]
+    fn collinear_control_points_flatten_to_one_segment() {
+        let mut points = vec![V2::new(0.0, 0.0)];
+        flatten_quadratic(V2::new(0.0, 0.0), V2::new(1.0, 0.0), V2::new(2.0, 0.0), FLATNESS_TOLERANCE, 0, &mut points);
+        assert_eq!(points, vec![V2::new(0.0, 0.0), V2::new(2.0, 0.0)]);
+
+        let curve = QuadraticBezier::new(V2::new(0.0, 0.0), V2::new(1.0, 0.0), V2::new(2.0, 0.0));
+        assert_eq!(curve.chain.segments.len(), 0, "a horizontal flattening has no rasterable segments");
+    }
+}
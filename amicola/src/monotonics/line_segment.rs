@@ -103,6 +103,53 @@ impl LineSegment {
     }
 
     pub fn normal(&self) -> V2 { self.normal }
+
+    /// Finds the point where this segment crosses `other`, if any.
+    ///
+    /// Uses the classic parametric line test: for segments `[a,b]` and
+    /// `[c,d]`, parallel segments (`denom == 0`) and segments whose crossing
+    /// falls outside either segment's `[0, 1]` range are rejected before any
+    /// division happens. A cheap `Bounds` overlap check short-circuits the
+    /// common case where the segments aren't even close.
+    pub fn intersection(&self, other: &LineSegment) -> Option<V2> {
+        if self.bounds.right < other.bounds.left
+            || other.bounds.right < self.bounds.left
+            || self.bounds.top < other.bounds.bottom
+            || other.bounds.top < self.bounds.bottom
+        {
+            return None;
+        }
+
+        let (a, b) = self.bookends();
+        let (c, d) = other.bookends();
+
+        let d10 = b - a;
+        let d32 = d - c;
+        let denom = d10.x * d32.y - d32.x * d10.y;
+        if denom == 0.0 {
+            return None;
+        }
+
+        let denom_is_pos = denom > 0.0;
+        let d02 = a - c;
+
+        let s_numer = d10.x * d02.y - d10.y * d02.x;
+        if (s_numer < 0.0) == denom_is_pos {
+            return None;
+        }
+
+        let t_numer = d32.x * d02.y - d32.y * d02.x;
+        if (t_numer < 0.0) == denom_is_pos {
+            return None;
+        }
+
+        if (s_numer > denom) == denom_is_pos || (t_numer > denom) == denom_is_pos {
+            return None;
+        }
+
+        let t = t_numer / denom;
+        Some(a + d10 * t)
+    }
 }
 
 impl Curve for LineSegment {
@@ -161,6 +208,34 @@ impl Curve for LineSegment {
     fn bounds(&self) -> &Bounds { &self.bounds }
 
     fn bookends(&self) -> (V2, V2) { (self.start, self.start + self.dir * self.length) }
+
+    /// Specialized over the generic `Curve::distance` default: closest point
+    /// on the segment to `p` via the standard clamped projection onto
+    /// `start..end`.
+    fn distance(&self, p: V2) -> f32 {
+        let pa = p - self.start;
+        let ba = self.dir * self.length;
+        let h = clamped_projection(pa, ba);
+        (pa - ba * h).norm()
+    }
+
+    fn distance_manhattan(&self, p: V2) -> f32 {
+        let pa = p - self.start;
+        let ba = self.dir * self.length;
+        let h = clamped_projection(pa, ba);
+        (pa.x - ba.x * h).abs().max((pa.y - ba.y * h).abs())
+    }
+}
+
+/// `dot(pa, ba) / dot(ba, ba)`, clamped to `[0, 1]` so the projected point
+/// never falls outside the segment.
+fn clamped_projection(pa: V2, ba: V2) -> f32 {
+    let denom = ba.x * ba.x + ba.y * ba.y;
+    if denom == 0.0 {
+        0.0
+    } else {
+        ((pa.x * ba.x + pa.y * ba.y) / denom).max(0.0).min(1.0)
+    }
 }
 
 #[cfg(test)]
@@ -228,6 +303,41 @@ mod test {
         );
     }
 
+    #[test]
+    fn intersection_crossing_segments() {
+        let a = LineSegment::new(V2::new(0.0, 0.0), V2::new(4.0, 4.0));
+        let b = LineSegment::new(V2::new(0.0, 4.0), V2::new(4.0, 0.0));
+        let hit = a.intersection(&b).expect("segments cross");
+        assert!((hit.x - 2.0).abs() < 1e-5);
+        assert!((hit.y - 2.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn intersection_parallel_segments() {
+        let a = LineSegment::new(V2::new(0.0, 0.0), V2::new(4.0, 4.0));
+        let b = LineSegment::new(V2::new(0.0, 1.0), V2::new(4.0, 5.0));
+        assert_eq!(a.intersection(&b), None);
+    }
+
+    #[test]
+    fn intersection_non_overlapping_segments() {
+        let a = LineSegment::new(V2::new(0.0, 0.0), V2::new(1.0, 1.0));
+        let b = LineSegment::new(V2::new(5.0, 5.0), V2::new(6.0, 4.0));
+        assert_eq!(a.intersection(&b), None);
+    }
+
+    #[test]
+    fn distance_to_perpendicular_point() {
+        let segment = LineSegment::new(V2::new(0.0, 0.0), V2::new(10.0, 0.0));
+        assert!((segment.distance(V2::new(5.0, 3.0)) - 3.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn distance_clamps_past_the_endpoints() {
+        let segment = LineSegment::new(V2::new(0.0, 0.0), V2::new(10.0, 0.0));
+        assert!((segment.distance(V2::new(15.0, 0.0)) - 5.0).abs() < 1e-5);
+    }
+
     #[test]
     fn sample() {
         let segment = LineSegment::new(V2::new(3.0, 1.0), V2::new(4.0, 2.0));
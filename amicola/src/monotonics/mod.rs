@@ -0,0 +1,129 @@
+//! Monotonic curve primitives.
+
+mod bezier;
+mod line_segment;
+
+pub use bezier::{CubicBezier, QuadraticBezier};
+pub use line_segment::{HorizontalNotRasterable, LineSegment, RasterableLineSegment};
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// A 2D point or vector.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct V2 {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl V2 {
+    pub fn new(x: f32, y: f32) -> Self { V2 { x, y } }
+
+    pub fn norm(&self) -> f32 { (self.x * self.x + self.y * self.y).sqrt() }
+
+    pub fn normalize(&self) -> Self {
+        let n = self.norm();
+        if n == 0.0 {
+            *self
+        } else {
+            V2::new(self.x / n, self.y / n)
+        }
+    }
+}
+
+impl Add for V2 {
+    type Output = V2;
+    fn add(self, rhs: V2) -> V2 { V2::new(self.x + rhs.x, self.y + rhs.y) }
+}
+
+impl Sub for V2 {
+    type Output = V2;
+    fn sub(self, rhs: V2) -> V2 { V2::new(self.x - rhs.x, self.y - rhs.y) }
+}
+
+impl Mul<f32> for V2 {
+    type Output = V2;
+    fn mul(self, rhs: f32) -> V2 { V2::new(self.x * rhs, self.y * rhs) }
+}
+
+impl Div<f32> for V2 {
+    type Output = V2;
+    fn div(self, rhs: f32) -> V2 { V2::new(self.x / rhs, self.y / rhs) }
+}
+
+impl Neg for V2 {
+    type Output = V2;
+    fn neg(self) -> V2 { V2::new(-self.x, -self.y) }
+}
+
+/// An axis-aligned bounding box.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Bounds {
+    pub left: f32,
+    pub right: f32,
+    pub top: f32,
+    pub bottom: f32,
+}
+
+/// A single scanline crossing: `axis` is the x (or y) coordinate of the
+/// crossing, `t` is how far along the curve it fell.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Intersection {
+    pub axis: f32,
+    pub t: f32,
+}
+
+/// A 2D shape sampleable along either axis; the basis of valora's scanline
+/// rasterizer.
+pub trait Curve {
+    fn sample_y(&self, y: f32) -> Option<Intersection>;
+
+    fn sample_x(&self, x: f32) -> Option<Intersection>;
+
+    fn sample_t(&self, t: f32) -> Option<V2>;
+
+    fn bounds(&self) -> &Bounds;
+
+    fn bookends(&self) -> (V2, V2);
+
+    /// Unsigned distance from `p` to the curve. The generic default walks
+    /// `sample_t` and takes the closest sample; `LineSegment` specializes
+    /// this with the exact clamped projection instead of sampling.
+    fn distance(&self, p: V2) -> f32 {
+        sampled_points(self).map(|point| (point - p).norm()).fold(f32::INFINITY, f32::min)
+    }
+
+    /// Counterpart to [`Curve::distance`] that measures the largest per-axis
+    /// offset, `max(|dx|, |dy|)`, rather than the Euclidean norm.
+    fn distance_manhattan(&self, p: V2) -> f32 {
+        sampled_points(self)
+            .map(|point| (point.x - p.x).abs().max((point.y - p.y).abs()))
+            .fold(f32::INFINITY, f32::min)
+    }
+
+    /// Finds a point where `self` crosses `other`, if any.
+    ///
+    /// Flattens both curves along their own `sample_t` into chains of
+    /// `LineSegment`s and tests every pair with `LineSegment::intersection`.
+    /// `LineSegment` does not override this; its exact, non-flattening test
+    /// is the inherent `LineSegment::intersection`.
+    fn intersects(&self, other: &dyn Curve) -> Option<V2>
+    where
+        Self: Sized,
+    {
+        let a: Vec<LineSegment> = flatten(self);
+        let b: Vec<LineSegment> = flatten(other);
+        a.iter().find_map(|sa| b.iter().find_map(|sb| sa.intersection(sb)))
+    }
+}
+
+/// How many samples the generic `Curve` defaults take along `sample_t` when
+/// a curve doesn't specialize them.
+const DEFAULT_SAMPLES: usize = 64;
+
+fn sampled_points<C: Curve + ?Sized>(curve: &C) -> impl Iterator<Item = V2> + '_ {
+    (0..=DEFAULT_SAMPLES).filter_map(move |i| curve.sample_t(i as f32 / DEFAULT_SAMPLES as f32))
+}
+
+fn flatten(curve: &dyn Curve) -> Vec<LineSegment> {
+    sampled_points(curve).collect::<Vec<_>>().windows(2).map(|w| LineSegment::new(w[0], w[1])).collect()
+}
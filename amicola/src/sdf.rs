@@ -0,0 +1,48 @@
+//! Signed-distance queries over whole shapes.
+
+use crate::monotonics::*;
+
+/// Unsigned distance from `p` to the closest of `segments`.
+pub fn distance(segments: &[LineSegment], p: V2) -> f32 {
+    segments.iter().map(|s| s.distance(p)).fold(f32::INFINITY, f32::min)
+}
+
+/// Signed distance from `p` to the closed shape traced by `segments`,
+/// negative when `p` is inside. The sign comes from the same even-odd
+/// scanline crossing count the rasterizer already uses: cast a ray along
+/// `-x` from `p` and count the `Curve::sample_y` crossings left of `p`.
+pub fn signed_distance(segments: &[LineSegment], p: V2) -> f32 {
+    let unsigned = distance(segments, p);
+    let crossings =
+        segments.iter().filter_map(|s| s.sample_y(p.y)).filter(|intersection| intersection.axis < p.x).count();
+
+    if crossings % 2 == 1 {
+        -unsigned
+    } else {
+        unsigned
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn unit_square() -> Vec<LineSegment> {
+        vec![
+            LineSegment::new(V2::new(0.0, 0.0), V2::new(1.0, 0.0)),
+            LineSegment::new(V2::new(1.0, 0.0), V2::new(1.0, 1.0)),
+            LineSegment::new(V2::new(1.0, 1.0), V2::new(0.0, 1.0)),
+            LineSegment::new(V2::new(0.0, 1.0), V2::new(0.0, 0.0)),
+        ]
+    }
+
+    #[test]
+    fn inside_the_square_is_negative() {
+        assert!(signed_distance(&unit_square(), V2::new(0.5, 0.5)) < 0.0);
+    }
+
+    #[test]
+    fn outside_the_square_is_positive() {
+        assert!(signed_distance(&unit_square(), V2::new(2.0, 0.5)) > 0.0);
+    }
+}
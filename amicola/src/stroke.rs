@@ -0,0 +1,294 @@
+//! Stroke-to-fill conversion.
+
+use crate::monotonics::*;
+
+/// How two consecutive stroked edges are connected at an interior vertex.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Join {
+    /// Extend both offset edges until they meet, falling back to `Bevel`
+    /// once the miter would exceed `limit` times the stroke width.
+    Miter { limit: f32 },
+    /// Connect the two offset edges with a single straight segment.
+    Bevel,
+    /// Connect the two offset edges with an arc fan of `segments` pieces,
+    /// centered on the path vertex the join turns around.
+    Round { segments: usize },
+}
+
+/// How the two ends of an open path are finished.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Cap {
+    /// No extension past the path endpoint.
+    Butt,
+    /// Extend the outline by half the width past the endpoint.
+    Square,
+    /// Cap with an arc fan of `segments` pieces, centered on the path
+    /// endpoint.
+    Round { segments: usize },
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct StrokeStyle {
+    pub width: f32,
+    pub join: Join,
+    pub cap: Cap,
+}
+
+/// Turns an ordered, connected polyline into a closed outline `style.width`
+/// units wide, ready for the existing fill pipeline.
+///
+/// `path` is assumed connected: the end of `path[i]` is the start of
+/// `path[i + 1]`. Each segment is offset by `±width / 2` along its
+/// [`LineSegment::normal`]; the offsets are stitched with `style.join` at
+/// interior vertices and finished with `style.cap` at the two open ends.
+pub fn stroke(path: &[LineSegment], style: &StrokeStyle) -> Vec<LineSegment> {
+    if path.is_empty() {
+        return Vec::new();
+    }
+
+    let half_width = style.width / 2.0;
+    let left: Vec<LineSegment> = path.iter().map(|s| s.translate(s.normal(), half_width)).collect();
+    let right: Vec<LineSegment> = path.iter().map(|s| s.translate(s.normal(), -half_width)).collect();
+
+    let mut outline = Vec::new();
+    outline.extend(stitch(path, &left, style));
+    outline.extend(cap(path, &left, &right, style, true));
+    outline.extend(stitch_rev(path, &right, style));
+    outline.extend(cap(path, &left, &right, style, false));
+    outline
+}
+
+fn stitch(original: &[LineSegment], side: &[LineSegment], style: &StrokeStyle) -> Vec<LineSegment> {
+    let mut out = Vec::new();
+    for i in 0..side.len() {
+        out.push(side[i]);
+        if let Some(next) = side.get(i + 1) {
+            add_join(&side[i], next, original[i].bookends().1, style, &mut out);
+        }
+    }
+    out
+}
+
+/// Same as [`stitch`], but walks `side` back-to-front, for the return edge
+/// of the outline.
+fn stitch_rev(original: &[LineSegment], side: &[LineSegment], style: &StrokeStyle) -> Vec<LineSegment> {
+    let mut out = Vec::new();
+    for i in (0..side.len()).rev() {
+        let (a, b) = side[i].bookends();
+        out.push(LineSegment::new(b, a));
+        if i > 0 {
+            let (a, b) = side[i - 1].bookends();
+            let reversed_current = LineSegment::new(side[i].bookends().1, side[i].bookends().0);
+            let reversed_prev = LineSegment::new(b, a);
+            add_join(&reversed_current, &reversed_prev, original[i].bookends().0, style, &mut out);
+        }
+    }
+    out
+}
+
+fn add_join(a: &LineSegment, b: &LineSegment, vertex: V2, style: &StrokeStyle, out: &mut Vec<LineSegment>) {
+    let (_, a_end) = a.bookends();
+    let (b_start, _) = b.bookends();
+    if a_end == b_start {
+        return;
+    }
+
+    match style.join {
+        Join::Bevel => out.push(LineSegment::new(a_end, b_start)),
+        Join::Round { segments } => out.extend(arc_fan(vertex, a_end, b_start, segments)),
+        Join::Miter { limit } => match infinite_line_intersection(a, b) {
+            Some(p) if (p - a_end).norm() <= limit * style.width => {
+                out.push(LineSegment::new(a_end, p));
+                out.push(LineSegment::new(p, b_start));
+            }
+            _ => out.push(LineSegment::new(a_end, b_start)),
+        },
+    }
+}
+
+/// Approximates the arc from `from` to `to` around `center` with a fan of
+/// `segments` straight pieces, sweeping whichever way is shorter.
+fn arc_fan(center: V2, from: V2, to: V2, segments: usize) -> Vec<LineSegment> {
+    if segments == 0 {
+        return vec![LineSegment::new(from, to)];
+    }
+
+    let radius = (from - center).norm();
+    let start_angle = (from.y - center.y).atan2(from.x - center.x);
+    let mut end_angle = (to.y - center.y).atan2(to.x - center.x);
+
+    let two_pi = std::f32::consts::PI * 2.0;
+    if end_angle - start_angle > std::f32::consts::PI {
+        end_angle -= two_pi;
+    } else if start_angle - end_angle > std::f32::consts::PI {
+        end_angle += two_pi;
+    }
+
+    let mut points = Vec::with_capacity(segments + 1);
+    points.push(from);
+    for i in 1..segments {
+        let t = i as f32 / segments as f32;
+        let angle = start_angle + (end_angle - start_angle) * t;
+        points.push(V2::new(center.x + radius * angle.cos(), center.y + radius * angle.sin()));
+    }
+    points.push(to);
+    points.windows(2).map(|w| LineSegment::new(w[0], w[1])).collect()
+}
+
+fn cap(
+    original: &[LineSegment],
+    left: &[LineSegment],
+    right: &[LineSegment],
+    style: &StrokeStyle,
+    at_start: bool,
+) -> Vec<LineSegment> {
+    let (left_end, right_end, vertex) = if at_start {
+        (left.first().unwrap().bookends().0, right.first().unwrap().bookends().0, original.first().unwrap().bookends().0)
+    } else {
+        (left.last().unwrap().bookends().1, right.last().unwrap().bookends().1, original.last().unwrap().bookends().1)
+    };
+
+    match style.cap {
+        Cap::Butt => vec![LineSegment::new(right_end, left_end)],
+        Cap::Round { segments } => arc_fan(vertex, right_end, left_end, segments),
+        Cap::Square => {
+            let reference = if at_start { left.first().unwrap() } else { left.last().unwrap() };
+            let (start, end) = reference.bookends();
+            let along = if at_start { start - end } else { end - start };
+            let half_width = (left_end - right_end).norm() / 2.0;
+            let extension = along.normalize() * half_width;
+            let left_ext = left_end + extension;
+            let right_ext = right_end + extension;
+            vec![
+                LineSegment::new(left_end, left_ext),
+                LineSegment::new(left_ext, right_ext),
+                LineSegment::new(right_ext, right_end),
+            ]
+        }
+    }
+}
+
+/// Intersection of the infinite lines through `a` and `b`, ignoring either
+/// segment's own extent (used to extend a miter past its segment bounds).
+fn infinite_line_intersection(a: &LineSegment, b: &LineSegment) -> Option<V2> {
+    let (a0, a1) = a.bookends();
+    let (b0, b1) = b.bookends();
+    let d10 = a1 - a0;
+    let d32 = b1 - b0;
+    let denom = d10.x * d32.y - d32.x * d10.y;
+    if denom == 0.0 {
+        return None;
+    }
+
+    let d02 = a0 - b0;
+    let t_numer = d32.x * d02.y - d32.y * d02.x;
+    let t = t_numer / denom;
+    Some(a0 + d10 * t)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn square_path() -> Vec<LineSegment> {
+        vec![
+            LineSegment::new(V2::new(0.0, 0.0), V2::new(10.0, 0.0)),
+            LineSegment::new(V2::new(10.0, 0.0), V2::new(10.0, 10.0)),
+            LineSegment::new(V2::new(10.0, 10.0), V2::new(0.0, 10.0)),
+        ]
+    }
+
+    #[test]
+    fn empty_path_strokes_to_nothing() {
+        let style = StrokeStyle { width: 2.0, join: Join::Bevel, cap: Cap::Butt };
+        assert_eq!(stroke(&[], &style), Vec::new());
+    }
+
+    #[test]
+    fn bevel_join_closes_the_outline() {
+        let style = StrokeStyle { width: 2.0, join: Join::Bevel, cap: Cap::Butt };
+        let outline = stroke(&square_path(), &style);
+        assert!(!outline.is_empty());
+
+        // A closed outline shares every endpoint between an even number of edges.
+        let endpoints: Vec<V2> = outline
+            .iter()
+            .flat_map(|s| {
+                let (a, b) = s.bookends();
+                vec![a, b]
+            })
+            .collect();
+        for p in &endpoints {
+            let shared = endpoints.iter().filter(|q| (**q - *p).norm() < 1e-4).count();
+            assert_eq!(shared % 2, 0, "outline endpoint {:?} is left open", p);
+        }
+    }
+
+    /// A shallow joint where the offset edges are nearly parallel, so their
+    /// endpoints at the vertex don't coincide (`a.bookends().1 != b.bookends().0`,
+    /// as `stroke()` itself would produce by offsetting each edge along its
+    /// own normal) and their miter point sits well away from the vertex.
+    fn shallow_joint() -> (LineSegment, LineSegment) {
+        (LineSegment::new(V2::new(0.0, 0.0), V2::new(10.0, 0.0)), LineSegment::new(V2::new(10.0, 0.2), V2::new(20.0, 0.0)))
+    }
+
+    #[test]
+    fn miter_join_falls_back_to_bevel_past_limit() {
+        let (a, b) = shallow_joint();
+        let mut out = Vec::new();
+        let style = StrokeStyle { width: 1.0, join: Join::Miter { limit: 0.001 }, cap: Cap::Butt };
+        add_join(&a, &b, a.bookends().1, &style, &mut out);
+        assert_eq!(out.len(), 1, "a near-zero miter limit should always fall back to a bevel");
+    }
+
+    #[test]
+    fn miter_limit_scales_with_stroke_width() {
+        let (a, b) = shallow_joint();
+        let miter_point = infinite_line_intersection(&a, &b).expect("non-parallel offset edges");
+        let miter_distance = (miter_point - a.bookends().1).norm();
+        assert!(miter_distance > 0.0, "fixture must actually produce a non-degenerate miter");
+
+        // `limit * width` must fall short of the miter distance for a narrow
+        // stroke and clear it for a wide one, so the same joint bevels at a
+        // width of an eighth of the miter distance but miters at the full one.
+        let mut narrow = Vec::new();
+        let narrow_style = StrokeStyle { width: miter_distance / 8.0, join: Join::Miter { limit: 4.0 }, cap: Cap::Butt };
+        add_join(&a, &b, a.bookends().1, &narrow_style, &mut narrow);
+        assert_eq!(narrow.len(), 1, "limit * width is smaller than the miter distance, so this should bevel");
+
+        let mut wide = Vec::new();
+        let wide_style = StrokeStyle { width: miter_distance, join: Join::Miter { limit: 4.0 }, cap: Cap::Butt };
+        add_join(&a, &b, a.bookends().1, &wide_style, &mut wide);
+        assert_eq!(wide.len(), 2, "limit * width comfortably covers the miter distance, so this should miter");
+    }
+
+    #[test]
+    fn round_join_midpoint_sits_on_the_arc_not_the_chord() {
+        let center = V2::new(0.0, 0.0);
+        let from = V2::new(1.0, 0.0);
+        let to = V2::new(0.0, 1.0);
+        let fan = arc_fan(center, from, to, 2);
+
+        let midpoint = fan[0].bookends().1;
+        assert!((midpoint.norm() - 1.0).abs() < 1e-4, "arc points must stay at the join radius from center");
+
+        let chord_midpoint = (from + to) * 0.5;
+        assert!((midpoint - chord_midpoint).norm() > 0.1, "a real arc point must not collapse onto the straight chord");
+    }
+
+    #[test]
+    fn round_cap_bulges_outward_from_the_path_endpoint() {
+        let style = StrokeStyle { width: 2.0, join: Join::Bevel, cap: Cap::Round { segments: 4 } };
+        let path = vec![LineSegment::new(V2::new(0.0, 0.0), V2::new(10.0, 0.0))];
+        let outline = stroke(&path, &style);
+        assert!(!outline.is_empty());
+
+        let fan = arc_fan(V2::new(10.0, 0.0), V2::new(10.0, -1.0), V2::new(10.0, 1.0), 4);
+        for segment in &fan {
+            let (start, _) = segment.bookends();
+            assert!(((start - V2::new(10.0, 0.0)).norm() - 1.0).abs() < 1e-4, "every arc point must sit exactly on the radius");
+        }
+        let tip = fan[2].bookends().0;
+        assert!(tip.x > 10.5, "the middle of the fan must sit past the endpoint, away from the path");
+    }
+}